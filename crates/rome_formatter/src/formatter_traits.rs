@@ -0,0 +1,38 @@
+//! Implements the language-independent [Format] trait for JavaScript's
+//! concrete syntax tokens, so any node's `format_fields` can call
+//! `.format(formatter)` directly on a token field.
+//!
+//! Each token attaches its own leading/trailing trivia (comments,
+//! whitespace) here. Because every token a node formats goes through this
+//! impl — not just the node's first and last — trivia on interior tokens is
+//! kept too, not just the ones at the node's edges.
+
+use crate::{Format, FormatElement, FormatResult, Formatter};
+use rslint_parser::SyntaxToken;
+
+impl Format for SyntaxToken {
+    fn format(&self, _formatter: &Formatter) -> FormatResult<FormatElement> {
+        let leading = trivia_element(
+            self.leading_trivia()
+                .pieces()
+                .map(|piece| piece.text().to_string()),
+        );
+        let trailing = trivia_element(
+            self.trailing_trivia()
+                .pieces()
+                .map(|piece| piece.text().to_string()),
+        );
+        let token = FormatElement::Token(self.text().to_string());
+
+        Ok(FormatElement::Concat(vec![leading, token, trailing]))
+    }
+}
+
+fn trivia_element(pieces: impl Iterator<Item = String>) -> FormatElement {
+    let text: String = pieces.collect();
+    if text.is_empty() {
+        FormatElement::Empty
+    } else {
+        FormatElement::Token(text)
+    }
+}
@@ -0,0 +1,63 @@
+//! The Rome formatter turns a syntax tree into an intermediate
+//! [FormatElement] tree, which the printer later turns into text.
+//!
+//! Formatting a node is split across two traits:
+//! - [Format] is language-independent. It's implemented directly for syntax
+//!   tokens, and for anything else every language formats the same way.
+//! - [FormatNode] is language-specific. `format_fields`, implemented once per
+//!   node type, formats that node's own fields by calling [Format::format] on
+//!   each token/child field, which is where trivia actually gets attached.
+//!
+//! Keeping [FormatResult]/[FormatError] free of anything JS-specific means
+//! other languages (CSS, JSON, ...) can reuse this same trait surface with
+//! their own token formatting.
+
+mod formatter_traits;
+pub mod ts;
+
+/// The intermediate representation produced by formatting a syntax tree,
+/// later printed to a string by the printer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FormatElement {
+    Empty,
+    Token(String),
+    Group(Box<FormatElement>),
+    Concat(Vec<FormatElement>),
+}
+
+/// An error that can occur while formatting a syntax tree
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FormatError {
+    /// A node was missing a child that's required to format it
+    MissingRequiredChild,
+}
+
+pub type FormatResult<T> = Result<T, FormatError>;
+
+/// Context threaded through formatting: indent level, printer options, etc.
+#[derive(Debug, Default)]
+pub struct Formatter {}
+
+/// Language-independent formatting, implemented directly for syntax tokens
+/// and anything else every language's formatter can format the same way.
+pub trait Format {
+    fn format(&self, formatter: &Formatter) -> FormatResult<FormatElement>;
+}
+
+/// Language-specific node formatting.
+///
+/// `format_fields` is implemented once per node type and only formats that
+/// node's own fields, by calling `.format(formatter)` on each token/child
+/// field. `format` is the entry point callers use regardless of node type;
+/// its default just delegates to `format_fields`, since trivia is already
+/// attached per-token by [Format]'s token impl rather than re-derived here
+/// from the node's first/last token — which would miss interior tokens and
+/// would require pulling a parser-specific node type into this trait.
+pub trait FormatNode {
+    /// Formats this node's own fields. Implemented per node type.
+    fn format_fields(&self, formatter: &Formatter) -> FormatResult<FormatElement>;
+
+    fn format(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+        self.format_fields(formatter)
+    }
+}
@@ -1,11 +1,10 @@
-use crate::formatter_traits::FormatTokenAndNode;
-use crate::{FormatElement, FormatResult, Formatter, ToFormatElement};
+use crate::{Format, FormatElement, FormatNode, FormatResult, Formatter};
 use rslint_parser::ast::TsEmptyExternalModuleDeclarationBody;
 use rslint_parser::ast::TsEmptyExternalModuleDeclarationBodyFields;
 
-impl ToFormatElement for TsEmptyExternalModuleDeclarationBody {
-    fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
+impl FormatNode for TsEmptyExternalModuleDeclarationBody {
+    fn format_fields(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
         let TsEmptyExternalModuleDeclarationBodyFields { semicolon_token } = self.as_fields();
         semicolon_token.format(formatter)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1 @@
+mod empty_external_module_declaration_body;
@@ -0,0 +1,192 @@
+//! Locates the `rome.json` that applies to a given directory, either by
+//! walking up the filesystem or by loading one the user pointed at directly
+//! with `--config-path`.
+
+use crate::configuration::formatter::{CliFormatterOptions, FormatterSettings};
+use crate::configuration::{Configuration, ConfigurationError, Language, CONFIG_FILE_NAME};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Starting from `start_dir`, walks upward looking for a `rome.json`. A
+/// `rome.json` that doesn't have `root: true` — or that exists but fails to
+/// parse — is skipped over the same way: it isn't the one we're looking
+/// for, so the walk just keeps going. The walk stops at the first directory
+/// whose `rome.json` parses *and* has `root: true`, or gives up once it runs
+/// out of parent directories.
+///
+/// Returns the resolved configuration (with its own `extends` chain already
+/// merged in) together with the path it was loaded from.
+pub fn discover_configuration(
+    start_dir: &Path,
+) -> Result<(Configuration, PathBuf), ConfigurationError> {
+    let mut dir = Some(start_dir);
+
+    while let Some(current) = dir {
+        let config_path = current.join(CONFIG_FILE_NAME);
+        if let Ok(content) = fs::read_to_string(&config_path) {
+            if let Ok(configuration) = Configuration::parse(&content) {
+                if configuration.root {
+                    let configuration = configuration.resolve_extends(&config_path)?;
+                    return Ok((configuration, config_path));
+                }
+            }
+        }
+        dir = current.parent();
+    }
+
+    Err(ConfigurationError::NotFound(start_dir.to_path_buf()))
+}
+
+/// Loads the `rome.json` inside `config_dir` directly, skipping the upward
+/// walk. Used when the user passes `--config-path`.
+pub fn load_configuration_from_path(
+    config_dir: &Path,
+) -> Result<(Configuration, PathBuf), ConfigurationError> {
+    let config_path = config_dir.join(CONFIG_FILE_NAME);
+    let content = fs::read_to_string(&config_path)
+        .map_err(|_| ConfigurationError::NotFound(config_path.clone()))?;
+    let configuration = Configuration::parse(&content)?.resolve_extends(&config_path)?;
+    Ok((configuration, config_path))
+}
+
+/// Resolves the configuration that applies to `working_dir`, then computes
+/// the effective [FormatterSettings] for `language` out of it.
+///
+/// If `config_path_override` is set (what a CLI's `--config-path` argument
+/// would carry), the `rome.json` is loaded from there directly and the
+/// upward walk is skipped. `cli_options` is layered on top as the final
+/// override, so flags passed on the command line always win over whatever
+/// the configuration file says.
+///
+/// This is the entry point a CLI driver would call (see the scope note on
+/// [`crate::configuration`] — no such driver exists in this tree yet).
+pub fn resolve_formatter_settings(
+    working_dir: &Path,
+    config_path_override: Option<&Path>,
+    language: Language,
+    cli_options: &CliFormatterOptions,
+) -> Result<(Configuration, PathBuf, FormatterSettings), ConfigurationError> {
+    let (configuration, config_path) = match config_path_override {
+        Some(config_dir) => load_configuration_from_path(config_dir)?,
+        None => discover_configuration(working_dir)?,
+    };
+
+    let settings = configuration
+        .formatter_settings_for_language(language)
+        .with_cli_options(cli_options);
+
+    Ok((configuration, config_path, settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Creates a fresh temp directory for a single test, so parallel test runs
+    /// don't trip over each other's files.
+    fn temp_dir() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "rome_discovery_test_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn discovers_a_root_config_in_an_ancestor_directory() {
+        let root_dir = temp_dir();
+        fs::write(root_dir.join(CONFIG_FILE_NAME), r#"{"root": true}"#).unwrap();
+        let nested_dir = root_dir.join("src").join("nested");
+        fs::create_dir_all(&nested_dir).unwrap();
+
+        let (configuration, config_path) = discover_configuration(&nested_dir).unwrap();
+
+        assert!(configuration.root);
+        assert_eq!(config_path, root_dir.join(CONFIG_FILE_NAME));
+    }
+
+    #[test]
+    fn skips_a_non_root_config_found_on_the_way_up() {
+        let root_dir = temp_dir();
+        fs::write(root_dir.join(CONFIG_FILE_NAME), r#"{"root": true}"#).unwrap();
+        let nested_dir = root_dir.join("nested");
+        fs::create_dir_all(&nested_dir).unwrap();
+        fs::write(
+            nested_dir.join(CONFIG_FILE_NAME),
+            r#"{"formatter": {"lineWidth": 100}}"#,
+        )
+        .unwrap();
+
+        let (configuration, config_path) = discover_configuration(&nested_dir).unwrap();
+
+        assert_eq!(config_path, root_dir.join(CONFIG_FILE_NAME));
+        // The non-root `rome.json` is skipped entirely, not merged in.
+        assert_eq!(configuration.formatter.line_width, None);
+    }
+
+    #[test]
+    fn skips_an_unparseable_non_root_config_and_keeps_walking_up() {
+        let root_dir = temp_dir();
+        fs::write(root_dir.join(CONFIG_FILE_NAME), r#"{"root": true}"#).unwrap();
+        let nested_dir = root_dir.join("nested");
+        fs::create_dir_all(&nested_dir).unwrap();
+        fs::write(nested_dir.join(CONFIG_FILE_NAME), "{ not json at all").unwrap();
+
+        let (configuration, config_path) = discover_configuration(&nested_dir).unwrap();
+
+        assert!(configuration.root);
+        assert_eq!(config_path, root_dir.join(CONFIG_FILE_NAME));
+    }
+
+    #[test]
+    fn reports_not_found_when_no_root_config_exists() {
+        let dir = temp_dir();
+
+        let error = discover_configuration(&dir).unwrap_err();
+
+        assert!(matches!(error, ConfigurationError::NotFound(_)));
+    }
+
+    #[test]
+    fn loads_a_config_directly_from_the_given_path() {
+        let dir = temp_dir();
+        fs::write(dir.join(CONFIG_FILE_NAME), r#"{"root": true}"#).unwrap();
+
+        let (configuration, config_path) = load_configuration_from_path(&dir).unwrap();
+
+        assert!(configuration.root);
+        assert_eq!(config_path, dir.join(CONFIG_FILE_NAME));
+    }
+
+    #[test]
+    fn reports_not_found_when_the_override_path_has_no_config() {
+        let dir = temp_dir();
+
+        let error = load_configuration_from_path(&dir).unwrap_err();
+
+        assert!(matches!(error, ConfigurationError::NotFound(_)));
+    }
+
+    #[test]
+    fn cli_options_override_whatever_discovery_produced() {
+        let dir = temp_dir();
+        fs::write(
+            dir.join(CONFIG_FILE_NAME),
+            r#"{"root": true, "formatter": {"lineWidth": 80}}"#,
+        )
+        .unwrap();
+        let cli_options = CliFormatterOptions {
+            line_width: Some(120),
+            ..Default::default()
+        };
+
+        let (_, _, settings) =
+            resolve_formatter_settings(&dir, None, Language::JavaScript, &cli_options).unwrap();
+
+        assert_eq!(settings.line_width, 120);
+    }
+}
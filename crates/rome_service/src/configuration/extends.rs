@@ -0,0 +1,233 @@
+//! Resolution of the `extends` field of [Configuration].
+//!
+//! Each entry of `extends` points at another `rome.json`-shaped file. That
+//! file is parsed, its own `extends` is resolved recursively, and the result
+//! is deep-merged underneath the configuration that declared it.
+
+use crate::configuration::{Configuration, ConfigurationError, Mergeable};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Resolves `configuration`'s `extends` chain, returning a single
+/// [Configuration] with every extended base merged in.
+///
+/// `config_path` is the path to `configuration`'s own file: relative
+/// `extends` entries are resolved against its parent directory, and it seeds
+/// cycle detection so a chain that extends straight back to this very file
+/// is rejected the first time it comes back around, not the second.
+///
+/// Cycle detection tracks only the path from the root down to the file
+/// currently being resolved, not every file visited anywhere in the tree: a
+/// "diamond" (e.g. `top.json` extends both `baseA.json` and `baseB.json`,
+/// and both of those extend a shared `common.json`) is legitimate and must
+/// not be rejected just because `common.json` was already visited on a
+/// sibling branch.
+pub(crate) fn resolve_extends(
+    configuration: Configuration,
+    config_path: &Path,
+) -> Result<Configuration, ConfigurationError> {
+    let mut path_stack = HashSet::new();
+    path_stack.insert(canonicalize(config_path).unwrap_or_else(|| config_path.to_path_buf()));
+    resolve_extends_with(configuration, config_path, &mut path_stack)
+}
+
+fn resolve_extends_with(
+    configuration: Configuration,
+    config_path: &Path,
+    path_stack: &mut HashSet<PathBuf>,
+) -> Result<Configuration, ConfigurationError> {
+    let mut resolved = Configuration::default();
+    let base_dir = config_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| config_path.to_path_buf());
+
+    for extend in &configuration.extends {
+        let extend_path = base_dir.join(extend);
+
+        let canonical = canonicalize(&extend_path).unwrap_or_else(|| extend_path.clone());
+        if !path_stack.insert(canonical.clone()) {
+            return Err(ConfigurationError::CircularExtends(extend_path));
+        }
+
+        let content = fs::read_to_string(&extend_path)
+            .map_err(|_| ConfigurationError::ExtendsNotFound(extend_path.clone()))?;
+        let base_configuration = Configuration::parse(&content).map_err(|error| {
+            ConfigurationError::ExtendsInvalid(extend_path.clone(), error.to_string())
+        })?;
+
+        let base_configuration =
+            resolve_extends_with(base_configuration, &extend_path, path_stack)?;
+        // Backtrack: `extend_path` is only a cycle for its own descendants,
+        // not for the next sibling in `configuration.extends`.
+        path_stack.remove(&canonical);
+
+        resolved = merge_configuration(resolved, base_configuration);
+    }
+
+    Ok(merge_configuration(resolved, configuration))
+}
+
+/// Deep-merges `extension` on top of `base`: scalar/`Option` fields are
+/// overridden by `extension` when present, nested configuration structs are
+/// merged recursively via [Mergeable].
+fn merge_configuration(base: Configuration, extension: Configuration) -> Configuration {
+    Configuration {
+        // `root` is only validated once the whole chain has been resolved
+        root: extension.root,
+        extends: extension.extends,
+        formatter: base.formatter.merge_with(extension.formatter),
+        javascript: base.javascript.merge_with(extension.javascript),
+        json: base.json.merge_with(extension.json),
+    }
+}
+
+fn canonicalize(path: &Path) -> Option<PathBuf> {
+    fs::canonicalize(path).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_extends;
+    use crate::configuration::{Configuration, ConfigurationError};
+    use std::fs;
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Creates a fresh temp directory for a single test, so parallel test runs
+    /// don't trip over each other's files.
+    fn temp_dir() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "rome_extends_test_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write(dir: &PathBuf, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn merges_a_multi_level_extends_chain() {
+        let dir = temp_dir();
+        write(
+            &dir,
+            "grandparent.json",
+            r#"{"formatter": {"lineWidth": 100, "quoteStyle": "single"}}"#,
+        );
+        write(
+            &dir,
+            "parent.json",
+            r#"{"extends": ["grandparent.json"], "formatter": {"lineWidth": 120}}"#,
+        );
+        let child_path = write(
+            &dir,
+            "child.json",
+            r#"{"root": true, "extends": ["parent.json"]}"#,
+        );
+
+        let configuration = Configuration::parse(&fs::read_to_string(&child_path).unwrap())
+            .unwrap()
+            .resolve_extends(&child_path)
+            .unwrap();
+
+        // `child.json` inherits `quoteStyle` from the grandparent and the
+        // `lineWidth` override set by the parent along the way.
+        assert_eq!(configuration.formatter.line_width, Some(120));
+        assert_eq!(
+            configuration.formatter.quote_style,
+            Some(crate::configuration::QuoteStyle::Single)
+        );
+    }
+
+    #[test]
+    fn allows_a_diamond_shaped_extends_graph() {
+        let dir = temp_dir();
+        write(&dir, "common.json", r#"{"formatter": {"lineWidth": 100}}"#);
+        write(
+            &dir,
+            "base_a.json",
+            r#"{"extends": ["common.json"], "formatter": {"quoteStyle": "single"}}"#,
+        );
+        write(
+            &dir,
+            "base_b.json",
+            r#"{"extends": ["common.json"], "formatter": {"indentSize": 4}}"#,
+        );
+        let top_path = write(
+            &dir,
+            "top.json",
+            r#"{"root": true, "extends": ["base_a.json", "base_b.json"]}"#,
+        );
+
+        // `common.json` is reached twice, once through each of `base_a.json`
+        // and `base_b.json` — that's a diamond, not a cycle, and must resolve.
+        let configuration = Configuration::parse(&fs::read_to_string(&top_path).unwrap())
+            .unwrap()
+            .resolve_extends(&top_path)
+            .unwrap();
+
+        assert_eq!(configuration.formatter.line_width, Some(100));
+        assert_eq!(
+            configuration.formatter.quote_style,
+            Some(crate::configuration::QuoteStyle::Single)
+        );
+        assert_eq!(configuration.formatter.indent_size, Some(4));
+    }
+
+    #[test]
+    fn child_not_mentioning_enabled_does_not_override_parents_disabled_formatter() {
+        let dir = temp_dir();
+        write(&dir, "base.json", r#"{"formatter": {"enabled": false}}"#);
+        let child_path = write(
+            &dir,
+            "child.json",
+            r#"{"root": true, "extends": ["base.json"], "formatter": {"lineWidth": 100}}"#,
+        );
+
+        // `child.json` never mentions `formatter.enabled`, so it must not
+        // silently re-enable the formatter the base turned off.
+        let configuration = Configuration::parse(&fs::read_to_string(&child_path).unwrap())
+            .unwrap()
+            .resolve_extends(&child_path)
+            .unwrap();
+
+        assert_eq!(configuration.formatter.enabled, Some(false));
+    }
+
+    #[test]
+    fn rejects_a_direct_two_file_cycle() {
+        let dir = temp_dir();
+        let a_path = dir.join("a.json");
+        let b_path = dir.join("b.json");
+        write(&dir, "a.json", r#"{"root": true, "extends": ["b.json"]}"#);
+        write(&dir, "b.json", r#"{"extends": ["a.json"]}"#);
+
+        let a = Configuration::parse(&fs::read_to_string(&a_path).unwrap()).unwrap();
+        let error = resolve_extends(a, &a_path).unwrap_err();
+
+        assert!(matches!(error, ConfigurationError::CircularExtends(_)));
+    }
+
+    #[test]
+    fn rejects_a_config_that_extends_itself() {
+        let dir = temp_dir();
+        let path = write(
+            &dir,
+            "self.json",
+            r#"{"root": true, "extends": ["self.json"]}"#,
+        );
+
+        let configuration = Configuration::parse(&fs::read_to_string(&path).unwrap()).unwrap();
+        let error = resolve_extends(configuration, &path).unwrap_err();
+
+        assert!(matches!(error, ConfigurationError::CircularExtends(_)));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::configuration::Mergeable;
+use serde::Deserialize;
+
+/// The style of the indentation
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PlainIndentStyle {
+    /// Tab
+    Tab,
+    /// Space
+    Space,
+}
+
+/// The style of quotes used around string literals
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum QuoteStyle {
+    /// Double
+    Double,
+    /// Single
+    Single,
+}
+
+/// The configuration of the formatter
+#[derive(Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default, rename_all = "camelCase")]
+pub struct FormatterConfiguration {
+    /// Is the formatter enabled for this project. Defaults to `true`
+    pub enabled: Option<bool>,
+
+    /// The style of the indentation. Defaults to `tab`
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation, when `indent_style` is `space`. Defaults to 2
+    pub indent_size: Option<u8>,
+
+    /// The width of a line that the formatter will try to avoid exceeding. Defaults to 80
+    pub line_width: Option<u16>,
+
+    /// The style of quotes used around string literals. Defaults to `double`
+    pub quote_style: Option<QuoteStyle>,
+}
+
+impl Default for FormatterConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: None,
+            indent_style: None,
+            indent_size: None,
+            line_width: None,
+            quote_style: None,
+        }
+    }
+}
+
+impl Mergeable for FormatterConfiguration {
+    fn merge_with(self, other: Self) -> Self {
+        Self {
+            enabled: other.enabled.or(self.enabled),
+            indent_style: other.indent_style.or(self.indent_style),
+            indent_size: other.indent_size.or(self.indent_size),
+            line_width: other.line_width.or(self.line_width),
+            quote_style: other.quote_style.or(self.quote_style),
+        }
+    }
+}
+
+/// The effective, fully-resolved formatter settings for a given file, after
+/// any per-language overrides have been layered on top of the global
+/// [FormatterConfiguration].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FormatterSettings {
+    pub enabled: bool,
+    pub indent_style: PlainIndentStyle,
+    pub indent_size: u8,
+    pub line_width: u16,
+    pub quote_style: QuoteStyle,
+}
+
+impl Default for FormatterSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: PlainIndentStyle::Tab,
+            indent_size: 2,
+            line_width: 80,
+            quote_style: QuoteStyle::Double,
+        }
+    }
+}
+
+impl From<&FormatterConfiguration> for FormatterSettings {
+    fn from(configuration: &FormatterConfiguration) -> Self {
+        let default = Self::default();
+        Self {
+            enabled: configuration.enabled.unwrap_or(default.enabled),
+            indent_style: configuration.indent_style.unwrap_or(default.indent_style),
+            indent_size: configuration.indent_size.unwrap_or(default.indent_size),
+            line_width: configuration.line_width.unwrap_or(default.line_width),
+            quote_style: configuration.quote_style.unwrap_or(default.quote_style),
+        }
+    }
+}
+
+impl FormatterSettings {
+    /// Layers a per-language override on top of these settings, letting any
+    /// field the override sets take precedence.
+    pub(crate) fn with_overrides(mut self, overrides: &LanguageFormatterConfiguration) -> Self {
+        if let Some(enabled) = overrides.enabled {
+            self.enabled = enabled;
+        }
+        if let Some(indent_style) = overrides.indent_style {
+            self.indent_style = indent_style;
+        }
+        if let Some(indent_size) = overrides.indent_size {
+            self.indent_size = indent_size;
+        }
+        if let Some(line_width) = overrides.line_width {
+            self.line_width = line_width;
+        }
+        if let Some(quote_style) = overrides.quote_style {
+            self.quote_style = quote_style;
+        }
+        self
+    }
+
+    /// Layers the formatting flags passed on the CLI on top of these
+    /// settings. Used as the final override layer, after configuration
+    /// discovery and any per-language overrides have already been applied.
+    pub fn with_cli_options(mut self, options: &CliFormatterOptions) -> Self {
+        if let Some(indent_style) = options.indent_style {
+            self.indent_style = indent_style;
+        }
+        if let Some(line_width) = options.line_width {
+            self.line_width = line_width;
+        }
+        if let Some(quote_style) = options.quote_style {
+            self.quote_style = quote_style;
+        }
+        self
+    }
+}
+
+/// The formatting flags a user can pass directly on the command line
+/// (e.g. `--indent-style`, `--line-width`, `--quote-style`), layered on top
+/// of whatever configuration discovery produced.
+///
+/// No argument parser populates this yet (see the scope note on
+/// [`crate::configuration`]); it's the shape a future one would hand to
+/// [FormatterSettings::with_cli_options].
+#[derive(Default, Debug, Clone, Eq, PartialEq)]
+pub struct CliFormatterOptions {
+    pub indent_style: Option<PlainIndentStyle>,
+    pub line_width: Option<u16>,
+    pub quote_style: Option<QuoteStyle>,
+}
+
+/// A per-language override of the global [FormatterConfiguration]. Every
+/// field mirrors its global counterpart but defaults to `None`, meaning "use
+/// whatever the global formatter settings say".
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default, rename_all = "camelCase")]
+pub struct LanguageFormatterConfiguration {
+    /// Overrides [FormatterConfiguration::enabled] for this language
+    pub enabled: Option<bool>,
+
+    /// Overrides [FormatterConfiguration::indent_style] for this language
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// Overrides [FormatterConfiguration::indent_size] for this language
+    pub indent_size: Option<u8>,
+
+    /// Overrides [FormatterConfiguration::line_width] for this language
+    pub line_width: Option<u16>,
+
+    /// Overrides [FormatterConfiguration::quote_style] for this language
+    pub quote_style: Option<QuoteStyle>,
+}
+
+impl Mergeable for LanguageFormatterConfiguration {
+    fn merge_with(self, other: Self) -> Self {
+        Self {
+            enabled: other.enabled.or(self.enabled),
+            indent_style: other.indent_style.or(self.indent_style),
+            indent_size: other.indent_size.or(self.indent_size),
+            line_width: other.line_width.or(self.line_width),
+            quote_style: other.quote_style.or(self.quote_style),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::configuration::formatter::LanguageFormatterConfiguration;
+use crate::configuration::Mergeable;
+use serde::Deserialize;
+
+/// Specific configuration for the JavaScript language
+#[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default, rename_all = "camelCase")]
+pub struct JavascriptConfiguration {
+    /// Formatter options that only apply to JavaScript files, layered on top
+    /// of the global `formatter` section
+    pub formatter: Option<LanguageFormatterConfiguration>,
+}
+
+impl Mergeable for JavascriptConfiguration {
+    fn merge_with(self, other: Self) -> Self {
+        Self {
+            formatter: match (self.formatter, other.formatter) {
+                (Some(base), Some(extension)) => Some(base.merge_with(extension)),
+                (base, extension) => extension.or(base),
+            },
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::configuration::Mergeable;
+use serde::Deserialize;
+
+/// Configuration of how `rome.json` itself is parsed
+#[derive(Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(default, rename_all = "camelCase")]
+pub struct JsonConfiguration {
+    /// Allows `//` and `/* */` comments, and trailing commas, inside `rome.json`. Defaults to `false`
+    pub allow_comments: Option<bool>,
+}
+
+impl Default for JsonConfiguration {
+    fn default() -> Self {
+        Self {
+            allow_comments: None,
+        }
+    }
+}
+
+impl Mergeable for JsonConfiguration {
+    fn merge_with(self, other: Self) -> Self {
+        Self {
+            allow_comments: other.allow_comments.or(self.allow_comments),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! A tolerant tokenizer for the JSONC dialect accepted by `rome.json` when
+//! [`JsonConfiguration::allow_comments`](crate::configuration::json::JsonConfiguration)
+//! is enabled.
+//!
+//! `//` line comments, `/* */` block comments and trailing commas are
+//! replaced with spaces (newlines are preserved) rather than removed, so the
+//! byte offsets `serde_json` reports on a parse error still line up with the
+//! original source.
+
+pub(crate) struct StrippedJson {
+    pub(crate) text: String,
+    pub(crate) had_comments_or_trailing_commas: bool,
+}
+
+/// Strips comments and trailing commas from `input`. Returns the byte offset
+/// of the opening `/*` on failure (currently only an unterminated block
+/// comment).
+pub(crate) fn strip_comments_and_trailing_commas(input: &str) -> Result<StrippedJson, usize> {
+    let bytes = input.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut had_comments_or_trailing_commas = false;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let byte = bytes[i];
+
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if byte == b'\\' {
+                escaped = true;
+            } else if byte == b'"' {
+                in_string = false;
+            }
+            i += 1;
+            continue;
+        }
+
+        match byte {
+            b'"' => {
+                in_string = true;
+                i += 1;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                let start = i;
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+                blank(&mut out, start, i);
+                had_comments_or_trailing_commas = true;
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                let start = i;
+                i += 2;
+                let mut closed = false;
+                while i + 1 < bytes.len() {
+                    if bytes[i] == b'*' && bytes[i + 1] == b'/' {
+                        i += 2;
+                        closed = true;
+                        break;
+                    }
+                    i += 1;
+                }
+                if !closed {
+                    return Err(start);
+                }
+                blank(&mut out, start, i);
+                had_comments_or_trailing_commas = true;
+            }
+            b',' => {
+                if matches!(
+                    bytes.get(skip_whitespace_and_comments(bytes, i + 1)),
+                    Some(b'}') | Some(b']')
+                ) {
+                    out[i] = b' ';
+                    had_comments_or_trailing_commas = true;
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    Ok(StrippedJson {
+        text: String::from_utf8(out).expect("stripping only replaces whole characters with ASCII spaces"),
+        had_comments_or_trailing_commas,
+    })
+}
+
+/// Returns the offset of the first byte at or after `start` that isn't
+/// whitespace or part of a `//` or `/* */` comment, so the trailing-comma
+/// lookahead isn't fooled by a comment sitting between the comma and the
+/// closing bracket (e.g. `{"a":1, // trailing\n}`).
+fn skip_whitespace_and_comments(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    loop {
+        while matches!(bytes.get(i), Some(b) if b.is_ascii_whitespace()) {
+            i += 1;
+        }
+        if bytes.get(i) == Some(&b'/') && bytes.get(i + 1) == Some(&b'/') {
+            while i < bytes.len() && bytes[i] != b'\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if bytes.get(i) == Some(&b'/') && bytes.get(i + 1) == Some(&b'*') {
+            i += 2;
+            while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                i += 1;
+            }
+            i = (i + 2).min(bytes.len());
+            continue;
+        }
+        return i;
+    }
+}
+
+/// Overwrites `input[start..end]` with spaces, leaving newlines untouched so
+/// line numbers in later error messages don't shift.
+fn blank(input: &mut [u8], start: usize, end: usize) {
+    for byte in &mut input[start..end] {
+        if *byte != b'\n' {
+            *byte = b' ';
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::strip_comments_and_trailing_commas;
+
+    #[test]
+    fn strips_line_and_block_comments() {
+        let stripped = strip_comments_and_trailing_commas(
+            "{\n  // a line comment\n  \"a\": /* inline */ 1\n}",
+        )
+        .unwrap();
+
+        assert!(stripped.had_comments_or_trailing_commas);
+        let parsed: serde_json::Value = serde_json::from_str(&stripped.text).unwrap();
+        assert_eq!(parsed["a"], 1);
+    }
+
+    #[test]
+    fn strips_a_trailing_comma_before_a_closing_brace_or_bracket() {
+        let stripped = strip_comments_and_trailing_commas(r#"{"a": [1, 2,], "b": 3,}"#).unwrap();
+
+        assert!(stripped.had_comments_or_trailing_commas);
+        let parsed: serde_json::Value = serde_json::from_str(&stripped.text).unwrap();
+        assert_eq!(parsed["a"][1], 2);
+        assert_eq!(parsed["b"], 3);
+    }
+
+    #[test]
+    fn strips_a_trailing_comma_with_a_line_comment_in_between() {
+        let stripped = strip_comments_and_trailing_commas("{\"a\": 1, // trailing\n}").unwrap();
+
+        assert!(stripped.had_comments_or_trailing_commas);
+        let parsed: serde_json::Value = serde_json::from_str(&stripped.text).unwrap();
+        assert_eq!(parsed["a"], 1);
+    }
+
+    #[test]
+    fn strips_a_trailing_comma_with_a_block_comment_in_between() {
+        let stripped = strip_comments_and_trailing_commas(r#"{"a": 1, /* trailing */ }"#).unwrap();
+
+        assert!(stripped.had_comments_or_trailing_commas);
+        let parsed: serde_json::Value = serde_json::from_str(&stripped.text).unwrap();
+        assert_eq!(parsed["a"], 1);
+    }
+
+    #[test]
+    fn does_not_treat_comment_like_text_inside_a_string_as_a_comment() {
+        let stripped =
+            strip_comments_and_trailing_commas(r#"{"a": "/* not a comment */"}"#).unwrap();
+
+        assert!(!stripped.had_comments_or_trailing_commas);
+        let parsed: serde_json::Value = serde_json::from_str(&stripped.text).unwrap();
+        assert_eq!(parsed["a"], "/* not a comment */");
+    }
+
+    #[test]
+    fn does_not_strip_a_comma_that_is_not_actually_trailing() {
+        // A double comma is invalid JSON either way; neither comma precedes a
+        // closing brace/bracket, so this tokenizer must leave both alone
+        // rather than mistake the second for a trailing one.
+        let stripped = strip_comments_and_trailing_commas("[1,,2]").unwrap();
+
+        assert!(!stripped.had_comments_or_trailing_commas);
+        assert_eq!(stripped.text, "[1,,2]");
+    }
+
+    #[test]
+    fn reports_the_offset_of_an_unterminated_block_comment() {
+        let error = strip_comments_and_trailing_commas("{\"a\": 1 /* never closed").unwrap_err();
+
+        assert_eq!(error, "{\"a\": 1 ".len());
+    }
+}
@@ -2,17 +2,47 @@
 //!
 //! The configuration is divided by "tool", and then it's possible to further customise it
 //! by language. The language might further options divided by tool.
+//!
+//! **Scope note:** two of the requests this module implements — emitting a
+//! JSON Schema for editor completion, and discovering `rome.json` plus a
+//! `--config-path` override — were specified as CLI-level features ("wire it
+//! to a CLI subcommand", "add a `--config-path` argument"). This tree has no
+//! CLI crate at all to wire either into, so [schema::print_configuration_schema]
+//! and [discovery::resolve_formatter_settings] are as far as either request
+//! can go here: library-side plumbing a CLI would call, not the user-facing
+//! feature itself. That's a scope/backlog mismatch rather than something
+//! fixable from inside this crate; the doc comments on those functions point
+//! back here instead of each repeating the explanation.
 
 use crate::configuration::formatter::FormatterConfiguration;
 use crate::configuration::javascript::JavascriptConfiguration;
+use crate::configuration::json::JsonConfiguration;
 use serde::Deserialize;
 use std::fmt::{Debug, Display, Formatter};
+use std::path::{Path, PathBuf};
 
+mod discovery;
+mod extends;
 mod formatter;
 mod javascript;
+mod json;
+mod jsonc;
+#[cfg(feature = "schemars")]
+mod schema;
+
+pub use discovery::{
+    discover_configuration, load_configuration_from_path, resolve_formatter_settings,
+};
+pub use formatter::{CliFormatterOptions, FormatterSettings, PlainIndentStyle, QuoteStyle};
+#[cfg(feature = "schemars")]
+pub use schema::{configuration_schema, print_configuration_schema};
+
+/// The name `rome.json` is expected to have on disk
+pub(crate) const CONFIG_FILE_NAME: &str = "rome.json";
 
 /// The configuration that is contained inside the file `rome.json`
 #[derive(Default, Debug, Eq, PartialEq, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(default)]
 pub struct Configuration {
     /// One root file should exist. Useful when `extends` comes into play.
@@ -20,42 +50,233 @@ pub struct Configuration {
     /// If `true`, this file should be the master configuration.
     pub root: bool,
 
+    /// A list of paths to other `rome.json` files to inherit from. Entries are
+    /// resolved relative to this configuration's file and merged in order,
+    /// with this configuration taking precedence over everything it extends.
+    pub extends: Vec<String>,
+
     /// The configuration of the formatter
     pub formatter: FormatterConfiguration,
 
     /// Specific configuration for the JavaScript language
     pub javascript: JavascriptConfiguration,
+
+    /// Configuration of how this very file is parsed
+    pub json: JsonConfiguration,
+}
+
+/// A language Rome can format, used to resolve per-language formatter overrides
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Language {
+    JavaScript,
 }
 
 impl Configuration {
     pub fn is_formatter_disabled(&self) -> bool {
-        !self.formatter.enabled
+        self.formatter.enabled == Some(false)
     }
+
+    /// Resolves the effective [FormatterSettings] for `language`, layering
+    /// that language's overrides (e.g. `javascript.formatter`) on top of the
+    /// global `formatter` section.
+    pub fn formatter_settings_for_language(&self, language: Language) -> FormatterSettings {
+        let settings = FormatterSettings::from(&self.formatter);
+        match language {
+            Language::JavaScript => match &self.javascript.formatter {
+                Some(overrides) => settings.with_overrides(overrides),
+                None => settings,
+            },
+        }
+    }
+
+    /// Parses the contents of a `rome.json` file.
+    ///
+    /// Comments and trailing commas are always stripped before deserializing,
+    /// but only accepted if [`JsonConfiguration::allow_comments`] is enabled;
+    /// otherwise their presence is reported as
+    /// [`ConfigurationError::CommentsNotAllowed`].
+    pub fn parse(content: &str) -> Result<Self, ConfigurationError> {
+        let stripped = jsonc::strip_comments_and_trailing_commas(content)
+            .map_err(ConfigurationError::MalformedComment)?;
+
+        let configuration: Configuration = serde_json::from_str(&stripped.text)
+            .map_err(|error| ConfigurationError::Parse(error.to_string()))?;
+
+        if stripped.had_comments_or_trailing_commas
+            && configuration.json.allow_comments != Some(true)
+        {
+            return Err(ConfigurationError::CommentsNotAllowed);
+        }
+
+        Ok(configuration)
+    }
+
+    /// Resolves the `extends` chain of this configuration and deep-merges it
+    /// in, then validates that the final, fully-resolved configuration has
+    /// `root` set to `true`.
+    ///
+    /// `config_path` is the path to this configuration's own file; relative
+    /// `extends` entries are resolved against its parent directory, and it
+    /// seeds cycle detection so a chain that extends straight back to this
+    /// very file is caught immediately instead of after a full extra loop.
+    pub fn resolve_extends(self, config_path: &Path) -> Result<Self, ConfigurationError> {
+        let resolved = extends::resolve_extends(self, config_path)?;
+        if !resolved.root {
+            return Err(ConfigurationError::NotRoot(config_path.to_path_buf()));
+        }
+        Ok(resolved)
+    }
+}
+
+/// Merges `other` on top of `self`, letting fields set in `other` take
+/// precedence over the ones already in `self`.
+pub(crate) trait Mergeable {
+    fn merge_with(self, other: Self) -> Self;
 }
 
 /// Series of errors that can be thrown while computing the configuration
 pub enum ConfigurationError {
-    /// Thrown when the main configuration file doesn't have
-    NotRoot,
+    /// Thrown when the main configuration file doesn't have `root` set to `true`
+    NotRoot(PathBuf),
+    /// Thrown when no usable `rome.json` is found. Carries the directory the upward walk
+    /// started from, or, when the location was given directly, the `rome.json` path that
+    /// couldn't be read
+    NotFound(PathBuf),
+    /// Thrown when a file referenced by `extends` couldn't be found or read
+    ExtendsNotFound(PathBuf),
+    /// Thrown when a file referenced by `extends` was found but failed to deserialize
+    ExtendsInvalid(PathBuf, String),
+    /// Thrown when an `extends` chain refers back to a file it already went through
+    CircularExtends(PathBuf),
+    /// Thrown when a block comment in `rome.json` is never closed
+    MalformedComment(usize),
+    /// Thrown when `rome.json` contains comments or trailing commas but `json.allowComments` is `false`
+    CommentsNotAllowed,
+    /// Thrown when `rome.json` is valid JSON(C) but doesn't deserialize into [Configuration]
+    Parse(String),
 }
 
 impl Debug for ConfigurationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ConfigurationError::NotRoot => std::fmt::Display::fmt(self, f),
-        }
+        std::fmt::Display::fmt(self, f)
     }
 }
 
 impl Display for ConfigurationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            ConfigurationError::NotRoot => {
+            ConfigurationError::NotRoot(path) => {
+                write!(
+                    f,
+                    "the configuration file at {} must have the field 'root' set to `true`",
+                    path.display()
+                )
+            }
+            ConfigurationError::NotFound(start_dir) => {
+                write!(
+                    f,
+                    "couldn't find a usable rome.json, searched from {}",
+                    start_dir.display()
+                )
+            }
+            ConfigurationError::ExtendsNotFound(path) => {
                 write!(
-                f,
-                "the main configuration file, rome.json, must have the field 'root' set to `true`"
-            )
+                    f,
+                    "couldn't find or read the configuration file extended at {}",
+                    path.display()
+                )
+            }
+            ConfigurationError::ExtendsInvalid(path, error) => {
+                write!(
+                    f,
+                    "the configuration file extended at {} is invalid: {error}",
+                    path.display()
+                )
+            }
+            ConfigurationError::CircularExtends(path) => {
+                write!(
+                    f,
+                    "circular `extends` detected: {} was already visited while resolving this configuration's extends chain",
+                    path.display()
+                )
+            }
+            ConfigurationError::MalformedComment(offset) => {
+                write!(f, "malformed comment in rome.json at byte offset {offset}")
+            }
+            ConfigurationError::CommentsNotAllowed => {
+                write!(
+                    f,
+                    "rome.json contains comments or trailing commas, but `json.allowComments` is not set to `true`"
+                )
+            }
+            ConfigurationError::Parse(error) => {
+                write!(f, "failed to parse rome.json: {error}")
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formatter_settings_for_language_falls_back_to_defaults() {
+        let configuration = Configuration::default();
+
+        let settings = configuration.formatter_settings_for_language(Language::JavaScript);
+
+        assert_eq!(settings, FormatterSettings::default());
+    }
+
+    #[test]
+    fn javascript_overrides_win_over_the_global_formatter_section() {
+        let configuration = Configuration::parse(
+            r#"{
+                "root": true,
+                "formatter": { "indentStyle": "space", "lineWidth": 80 },
+                "javascript": { "formatter": { "lineWidth": 100 } }
+            }"#,
+        )
+        .unwrap();
+
+        let settings = configuration.formatter_settings_for_language(Language::JavaScript);
+
+        // `indentStyle` only set globally, so it carries through unchanged...
+        assert_eq!(settings.indent_style, PlainIndentStyle::Space);
+        // ...but `lineWidth` is overridden by the JavaScript-specific section.
+        assert_eq!(settings.line_width, 100);
+    }
+
+    #[test]
+    fn parses_rejects_json_that_does_not_match_the_configuration_shape() {
+        let error =
+            Configuration::parse(r#"{"formatter": {"lineWidth": "not a number"}}"#).unwrap_err();
+
+        assert!(matches!(error, ConfigurationError::Parse(_)));
+    }
+
+    #[test]
+    fn is_formatter_disabled_only_when_explicitly_set_to_false() {
+        assert!(!Configuration::default().is_formatter_disabled());
+
+        let disabled = Configuration::parse(r#"{"formatter": {"enabled": false}}"#).unwrap();
+        assert!(disabled.is_formatter_disabled());
+
+        let unset = Configuration::parse(r#"{"formatter": {"lineWidth": 100}}"#).unwrap();
+        assert!(!unset.is_formatter_disabled());
+    }
+
+    #[test]
+    fn accepts_quote_style_as_a_camel_case_tag() {
+        // `QuoteStyle` deserializes from a lowercase tag; guards against the
+        // rename_all convention silently drifting off camelCase.
+        let configuration =
+            Configuration::parse(r#"{"formatter": {"quoteStyle": "single"}}"#).unwrap();
+
+        assert_eq!(
+            configuration.formatter.quote_style,
+            Some(QuoteStyle::Single)
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! Generates the JSON Schema for `rome.json`, so editors can offer
+//! completion and validation against it once a `$schema` reference points
+//! here.
+//!
+//! Gated behind the `schemars` feature: the derive and the `schemars` crate
+//! itself are only pulled in when something actually needs the schema.
+//!
+//! See the scope note on [`crate::configuration`] for why this stops at a
+//! library function rather than a CLI subcommand.
+
+use crate::configuration::Configuration;
+
+/// Serializes the JSON Schema describing [Configuration] to a pretty-printed string.
+pub fn configuration_schema() -> String {
+    let schema = schemars::schema_for!(Configuration);
+    serde_json::to_string_pretty(&schema).expect("a generated schema is always valid JSON")
+}
+
+/// Prints the JSON Schema for `rome.json` to stdout. Intended to be called
+/// by a CLI's schema-emitting subcommand (see the module doc).
+pub fn print_configuration_schema() {
+    println!("{}", configuration_schema());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::configuration_schema;
+
+    #[test]
+    fn generates_valid_json() {
+        let schema: serde_json::Value = serde_json::from_str(&configuration_schema()).unwrap();
+
+        assert!(schema["properties"]["root"].is_object());
+        assert!(schema["properties"]["formatter"].is_object());
+        assert!(schema["properties"]["javascript"].is_object());
+    }
+
+    #[test]
+    fn marks_every_top_level_field_as_defaulted_rather_than_required() {
+        // `Configuration` is `#[serde(default)]`, so every field is optional
+        // in `rome.json` — the schema's `required` list should reflect that.
+        let schema: serde_json::Value = serde_json::from_str(&configuration_schema()).unwrap();
+
+        let required = schema["required"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        assert!(required.is_empty());
+    }
+}